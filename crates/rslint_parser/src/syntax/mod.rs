@@ -0,0 +1,9 @@
+///! Grammar entry points for `rslint_parser`, organized one module per syntactic area.
+///!
+///! This snapshot only carries the modules touched by the pattern/recovery/list-parsing work
+///! below; the rest of the grammar (`expr`, `stmt`, `class`, ...) lives in sibling modules that
+///! aren't part of this slice of the tree but are assumed declared here in the full crate.
+pub(crate) mod pattern;
+pub(crate) mod recovery;
+pub(crate) mod separated_list;
+pub(crate) mod typescript;
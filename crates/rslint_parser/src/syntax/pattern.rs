@@ -1,12 +1,15 @@
 ///! Provides traits for parsing pattern like nodes
-use crate::parser::ParserProgress;
 use crate::syntax::expr::{expr_or_assignment, EXPR_RECOVERY_SET};
+use crate::syntax::recovery::{recover_to_boundary, PATTERN_RECOVERY_SET};
+use crate::syntax::separated_list::ParseSeparatedList;
+use crate::syntax::typescript::parse_pattern_type_annotation;
 use crate::ParsedSyntax::{Absent, Present};
 use crate::{CompletedMarker, Invalid, ParseRecovery, ParsedSyntax, Parser, ParserState, Valid};
 use crate::{ConditionalSyntax, TokenSet};
 use rslint_errors::Diagnostic;
 use rslint_syntax::SyntaxKind::{EOF, JS_ARRAY_HOLE};
 use rslint_syntax::{SyntaxKind, T};
+use std::marker::PhantomData;
 use std::ops::Range;
 
 /// Trait for parsing a pattern with an optional default of the form `pattern = default`
@@ -21,9 +24,18 @@ pub(crate) trait ParseWithDefaultPattern {
 	/// Parses a pattern (without its default value)
 	fn parse_pattern(&self, p: &mut Parser) -> ParsedSyntax<CompletedMarker>;
 
-	/// Parses a pattern and wraps it in a pattern with default if a `=` token follows the pattern
+	// test typed_array_pattern
+	// let [a, b]: [number, string] = x;
+
+	// test typed_object_pattern
+	// let { a }: Props = x;
+
+	/// Parses a pattern, followed by an optional TypeScript type annotation (e.g. the
+	/// `: [number, string]` in `const [a, b]: [number, string] = x`), and wraps the pattern in a
+	/// pattern with default if a `=` token follows.
 	fn parse_pattern_with_optional_default(&self, p: &mut Parser) -> ParsedSyntax<CompletedMarker> {
 		let pattern = self.parse_pattern(p);
+		let _type_annotation = parse_pattern_type_annotation(p);
 
 		if p.at(T![=]) {
 			let with_default =
@@ -56,6 +68,12 @@ pub(crate) trait ParseArrayPattern<P: ParseWithDefaultPattern> {
 	/// Creates a pattern with default instance. Used to parse the array elements.
 	fn pattern_with_default(&self) -> P;
 
+	// test array_pattern
+	// let [a, b, , c = "c", ...rest] = x;
+
+	// test_err array_pattern_err
+	// let [a, = "c"] = x;
+
 	/// Tries to parse an array like pattern
 	fn parse_array_pattern(&self, p: &mut Parser) -> ParsedSyntax<CompletedMarker> {
 		if !p.at(T!['[']) {
@@ -65,8 +83,6 @@ pub(crate) trait ParseArrayPattern<P: ParseWithDefaultPattern> {
 		let m = p.start();
 
 		p.bump(T!['[']);
-		let elements = p.start();
-		let mut progress = ParserProgress::default();
 
 		{
 			let guard = &mut *p.with_state(ParserState {
@@ -74,32 +90,9 @@ pub(crate) trait ParseArrayPattern<P: ParseWithDefaultPattern> {
 				..p.state.clone()
 			});
 
-			while !guard.at(EOF) && !guard.at(T![']']) {
-				progress.assert_progressing(guard);
-
-				let recovery = ParseRecovery::new(
-					Self::unknown_pattern_kind(),
-					token_set!(EOF, T![,], T![']'], T![=], T![;], T![...], T![')']),
-				)
-				.enable_recovery_on_line_break();
-
-				let element = self
-					.parse_any_array_element(guard, &recovery)
-					.or_invalid_to_unknown(guard, Self::unknown_pattern_kind())
-					.or_recover(guard, &recovery, Self::expected_element_error);
-
-				if element.is_err() {
-					// Failed to recover
-					break;
-				}
-
-				if !guard.at(T![']']) {
-					guard.expect_required(T![,]);
-				}
-			}
+			ArrayPatternElements(self, PhantomData).parse_list(guard);
 		}
 
-		elements.complete(p, Self::list_kind());
 		p.expect_required(T![']']);
 
 		Present(m.complete(p, Self::array_pattern_kind()))
@@ -109,13 +102,13 @@ pub(crate) trait ParseArrayPattern<P: ParseWithDefaultPattern> {
 	fn parse_any_array_element(
 		&self,
 		p: &mut Parser,
-		recovery: &ParseRecovery,
+		_recovery: &ParseRecovery,
 	) -> ParsedSyntax<ConditionalSyntax> {
 		match p.cur() {
 			T![,] => Present(Valid(p.start().complete(p, JS_ARRAY_HOLE))),
-			T![...] => self
-				.parse_rest_pattern(p)
-				.map(|rest_pattern| validate_rest_pattern(p, rest_pattern, T![']'], recovery)),
+			T![...] => self.parse_rest_pattern(p).map(|rest_pattern| {
+				validate_rest_pattern(p, rest_pattern, T![']'], Self::unknown_pattern_kind())
+			}),
 			_ => self
 				.pattern_with_default()
 				.parse_pattern_with_optional_default(p)
@@ -123,6 +116,12 @@ pub(crate) trait ParseArrayPattern<P: ParseWithDefaultPattern> {
 		}
 	}
 
+	// test rest_pattern
+	// let [...rest] = x;
+
+	// test_err rest_pattern_err
+	// let [...] = x;
+
 	/// Parses a rest element
 	fn parse_rest_pattern(&self, p: &mut Parser) -> ParsedSyntax<CompletedMarker> {
 		if !p.at(T![...]) {
@@ -143,6 +142,52 @@ pub(crate) trait ParseArrayPattern<P: ParseWithDefaultPattern> {
 	}
 }
 
+/// Adapts a [ParseArrayPattern] implementor to [ParseSeparatedList] so
+/// [ParseArrayPattern::parse_array_pattern] can reuse the shared list-parsing loop.
+struct ArrayPatternElements<'p, P, T: ParseArrayPattern<P>>(&'p T, PhantomData<P>);
+
+impl<'p, P: ParseWithDefaultPattern, T: ParseArrayPattern<P>> ParseSeparatedList
+	for ArrayPatternElements<'p, P, T>
+{
+	fn separator() -> SyntaxKind {
+		T![,]
+	}
+
+	fn terminator() -> SyntaxKind {
+		T![']']
+	}
+
+	fn list_kind() -> SyntaxKind {
+		T::list_kind()
+	}
+
+	fn unknown_element_kind() -> SyntaxKind {
+		T::unknown_pattern_kind()
+	}
+
+	fn recovery_set() -> TokenSet {
+		PATTERN_RECOVERY_SET
+	}
+
+	// A bare `,` is a meaningful element here (a JS_ARRAY_HOLE), so hand it to parse_element
+	// instead of having the generic loop treat it as a missing element to skip.
+	fn missing_element_on_leading_separator() -> bool {
+		false
+	}
+
+	fn expected_element_error(p: &Parser, range: Range<usize>) -> Diagnostic {
+		T::expected_element_error(p, range)
+	}
+
+	fn parse_element(
+		&self,
+		p: &mut Parser,
+		recovery: &ParseRecovery,
+	) -> ParsedSyntax<ConditionalSyntax> {
+		self.0.parse_any_array_element(p, recovery)
+	}
+}
+
 /// Trait for parsing an object pattern like node of the form `{ a, b: c}`
 pub(crate) trait ParseObjectPattern {
 	/// Kind used when recovering from invalid properties.
@@ -154,6 +199,12 @@ pub(crate) trait ParseObjectPattern {
 	/// Creates a diagnostic saying that a property is expected at the passed in range that isn't present.
 	fn expected_property_pattern_error(p: &Parser, range: Range<usize>) -> Diagnostic;
 
+	// test object_pattern
+	// let { a, b: c, ...rest } = x;
+
+	// test_err object_pattern_err
+	// let { , a } = x;
+
 	/// Parses the object pattern like node
 	fn parse_object_pattern(&self, p: &mut Parser) -> ParsedSyntax<CompletedMarker> {
 		if !p.at(T!['{']) {
@@ -163,8 +214,6 @@ pub(crate) trait ParseObjectPattern {
 		let m = p.start();
 
 		p.bump(T!['{']);
-		let elements = p.start();
-		let mut progress = ParserProgress::default();
 
 		{
 			// TODO remove after migrating expression to `ParsedSyntax`
@@ -173,41 +222,9 @@ pub(crate) trait ParseObjectPattern {
 				..p.state.clone()
 			});
 
-			while !guard.at(T!['}']) {
-				progress.assert_progressing(guard);
-
-				if guard.at(T![,]) {
-					// missing element
-					guard.missing();
-					guard.error(Self::expected_property_pattern_error(
-						guard,
-						guard.cur_tok().range,
-					));
-					guard.bump_any(); // bump ,
-					continue;
-				}
-				let recovery_set = ParseRecovery::new(
-					Self::unknown_pattern_kind(),
-					token_set!(EOF, T![,], T!['}'], T![...], T![;], T![')']),
-				)
-				.enable_recovery_on_line_break();
-
-				let recover_result = self
-					.parse_any_property_pattern(guard, &recovery_set)
-					.or_invalid_to_unknown(guard, Self::unknown_pattern_kind())
-					.or_recover(guard, &recovery_set, Self::expected_property_pattern_error);
-
-				if recover_result.is_err() {
-					break;
-				}
-
-				if !guard.at(T!['}']) {
-					guard.expect_required(T![,]);
-				}
-			}
+			ObjectPatternProperties(self).parse_list(guard);
 		}
 
-		elements.complete(p, Self::list_kind());
 		p.expect(T!['}']);
 
 		Present(m.complete(p, Self::object_pattern_kind()))
@@ -217,11 +234,12 @@ pub(crate) trait ParseObjectPattern {
 	fn parse_any_property_pattern(
 		&self,
 		p: &mut Parser,
-		recovery: &ParseRecovery,
+		_recovery: &ParseRecovery,
 	) -> ParsedSyntax<ConditionalSyntax> {
 		if p.at(T![...]) {
-			self.parse_rest_property_pattern(p)
-				.map(|rest_pattern| validate_rest_pattern(p, rest_pattern, T!['}'], recovery))
+			self.parse_rest_property_pattern(p).map(|rest_pattern| {
+				validate_rest_pattern(p, rest_pattern, T!['}'], Self::unknown_pattern_kind())
+			})
 		} else {
 			self.parse_property_pattern(p).into_valid()
 		}
@@ -234,6 +252,47 @@ pub(crate) trait ParseObjectPattern {
 	fn parse_rest_property_pattern(&self, p: &mut Parser) -> ParsedSyntax<CompletedMarker>;
 }
 
+/// Adapts a [ParseObjectPattern] implementor to [ParseSeparatedList] so
+/// [ParseObjectPattern::parse_object_pattern] can reuse the shared list-parsing loop. A stray
+/// leading `,` (e.g. `{ , a }`) is reported as a missing property and skipped by
+/// [ParseSeparatedList::parse_list]'s default `missing_element_on_leading_separator` handling,
+/// the same "bump and continue" behaviour the hand-written loop used to have.
+struct ObjectPatternProperties<'p, T: ParseObjectPattern>(&'p T);
+
+impl<'p, T: ParseObjectPattern> ParseSeparatedList for ObjectPatternProperties<'p, T> {
+	fn separator() -> SyntaxKind {
+		T![,]
+	}
+
+	fn terminator() -> SyntaxKind {
+		T!['}']
+	}
+
+	fn list_kind() -> SyntaxKind {
+		T::list_kind()
+	}
+
+	fn unknown_element_kind() -> SyntaxKind {
+		T::unknown_pattern_kind()
+	}
+
+	fn recovery_set() -> TokenSet {
+		PATTERN_RECOVERY_SET
+	}
+
+	fn expected_element_error(p: &Parser, range: Range<usize>) -> Diagnostic {
+		T::expected_property_pattern_error(p, range)
+	}
+
+	fn parse_element(
+		&self,
+		p: &mut Parser,
+		recovery: &ParseRecovery,
+	) -> ParsedSyntax<ConditionalSyntax> {
+		self.0.parse_any_property_pattern(p, recovery)
+	}
+}
+
 /// Validates if the parsed completed rest marker is a valid rest element inside of a
 /// array or object assignment target and converts it to an unknown assignment target if not.
 /// A rest element must be:
@@ -245,7 +304,7 @@ fn validate_rest_pattern(
 	p: &mut Parser,
 	rest: CompletedMarker,
 	end_token: SyntaxKind,
-	recovery: &ParseRecovery,
+	unknown_kind: SyntaxKind,
 ) -> ConditionalSyntax {
 	if p.at(end_token) {
 		return Valid(rest);
@@ -258,7 +317,7 @@ fn validate_rest_pattern(
 		let kind = rest.kind();
 		p.bump(T![=]);
 
-		if let Ok(recovered) = recovery.recover(p) {
+		if let Some(recovered) = recover_to_boundary(p, unknown_kind, PATTERN_RECOVERY_SET, true) {
 			recovered.undo_completion(p).abandon(p); // append recovered content to parent
 		}
 		p.error(
@@ -0,0 +1,56 @@
+///! Centralized recovery token-sets and a "recover to the next boundary" recovery mode, modeled
+///! on rust-analyzer's `ITEM_RECOVERY_SET`.
+///!
+///! Before this module, every parser loop that needed to bail out of a malformed construct
+///! hand-rolled its own `token_set!(EOF, T![,], T![']'], ...)` literal. Small inconsistencies
+///! between those literals meant a single broken element could cascade into many spurious
+///! diagnostics before the parser found its footing again. Routing recovery through the shared
+///! sets here and through [recover_to_boundary] keeps that behaviour consistent across every
+///! caller.
+use crate::{CompletedMarker, Parser};
+use rslint_syntax::SyntaxKind::EOF;
+use rslint_syntax::{SyntaxKind, T};
+use crate::TokenSet;
+
+/// Tokens that can begin, separate, or close a pattern element. Used as the recovery boundary
+/// inside array and object patterns.
+pub(crate) const PATTERN_RECOVERY_SET: TokenSet = token_set![
+	EOF,
+	T![,],
+	T![']'],
+	T!['}'],
+	T![=],
+	T![;],
+	T![...],
+	T![')'],
+];
+
+/// Recovers from an unexpected token by bumping tokens into a bogus `kind` node until the
+/// parser reaches a token in `recovery_set`, `EOF`, or (if `recover_on_line_break` is set) a
+/// line break, rather than stopping after a single token like [crate::ParseRecovery::recover]
+/// does. This is the "skip to the next boundary" mode: it trades precision of the bogus node's
+/// contents for not re-deriving a diagnostic for every token the broken construct swallows.
+///
+/// Returns `None` if the parser is already at a recovery token (there is nothing to recover).
+pub(crate) fn recover_to_boundary(
+	p: &mut Parser,
+	kind: SyntaxKind,
+	recovery_set: TokenSet,
+	recover_on_line_break: bool,
+) -> Option<CompletedMarker> {
+	if p.at(EOF) || recovery_set.contains(p.cur()) {
+		return None;
+	}
+
+	let m = p.start();
+
+	while !p.at(EOF) && !recovery_set.contains(p.cur()) {
+		if recover_on_line_break && p.has_preceding_line_break() {
+			break;
+		}
+
+		p.bump_any();
+	}
+
+	Some(m.complete(p, kind))
+}
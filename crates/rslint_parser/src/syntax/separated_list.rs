@@ -0,0 +1,97 @@
+///! A generic separated-list parsing loop shared across pattern, parameter, and argument lists.
+///!
+///! `parse_array_pattern` and `parse_object_pattern` used to duplicate almost the same loop:
+///! start a list marker, loop until the closing token or EOF, assert parser progress, parse one
+///! element with recovery, and `expect_required` the separator unless at the closing token. This
+///! trait pulls that loop out once so every comma-separated construct in the grammar (patterns
+///! today, call arguments and function parameters as they're migrated) gets the same trailing
+///! separator and missing-element handling instead of each reinventing it slightly differently.
+use crate::TokenSet;
+use crate::{CompletedMarker, ConditionalSyntax, ParseRecovery, ParsedSyntax, Parser, ParserProgress};
+use rslint_errors::Diagnostic;
+use rslint_syntax::SyntaxKind::EOF;
+use rslint_syntax::SyntaxKind;
+use std::ops::Range;
+
+/// Trait for parsing a list of elements separated by a token (usually `,`) and closed by a
+/// terminator token (usually `]`, `}`, or `)`).
+pub(crate) trait ParseSeparatedList {
+	/// The token that separates list elements.
+	fn separator() -> SyntaxKind;
+	/// The token that closes the list.
+	fn terminator() -> SyntaxKind;
+	/// The kind of the node wrapping the whole list.
+	fn list_kind() -> SyntaxKind;
+	/// The kind used for an element that couldn't be parsed as anything recognizable.
+	fn unknown_element_kind() -> SyntaxKind;
+	/// The recovery set used while recovering from an invalid element.
+	fn recovery_set() -> TokenSet;
+	/// Whether a trailing separator right before the terminator is allowed, e.g. the second `,`
+	/// in `[a, b,]`. Every comma-separated construct in the grammar allows this today.
+	fn allow_trailing_separator() -> bool {
+		true
+	}
+	/// Whether encountering the separator before any element content (e.g. the leading `,` in
+	/// `{ , a }`) is treated as a missing element that gets skipped over, instead of being handed
+	/// to [Self::parse_element]. Lists whose element grammar gives the bare separator its own
+	/// meaning (e.g. an array pattern's `,` denoting a hole) should override this to `false` so
+	/// [Self::parse_element] sees it.
+	fn missing_element_on_leading_separator() -> bool {
+		true
+	}
+	/// Creates a diagnostic saying that an element was expected at the given range.
+	fn expected_element_error(p: &Parser, range: Range<usize>) -> Diagnostic;
+	/// Parses a single list element.
+	fn parse_element(
+		&self,
+		p: &mut Parser,
+		recovery: &ParseRecovery,
+	) -> ParsedSyntax<ConditionalSyntax>;
+
+	/// Parses the full separated list and returns the completed list marker. Assumes the parser
+	/// is positioned right after the list's opening token, and leaves the closing token unbumped
+	/// for the caller to `expect`.
+	fn parse_list(&self, p: &mut Parser) -> CompletedMarker {
+		let m = p.start();
+		let mut progress = ParserProgress::default();
+
+		while !p.at(EOF) && !p.at(Self::terminator()) {
+			progress.assert_progressing(p);
+
+			if Self::missing_element_on_leading_separator() && p.at(Self::separator()) {
+				p.missing();
+				p.error(Self::expected_element_error(p, p.cur_tok().range));
+				p.bump_any(); // bump the stray separator
+				continue;
+			}
+
+			let recovery = ParseRecovery::new(Self::unknown_element_kind(), Self::recovery_set())
+				.enable_recovery_on_line_break();
+
+			let element = self
+				.parse_element(p, &recovery)
+				.or_invalid_to_unknown(p, Self::unknown_element_kind())
+				.or_recover(p, &recovery, Self::expected_element_error);
+
+			if element.is_err() {
+				break;
+			}
+
+			if p.at(Self::separator()) {
+				let separator_range = p.cur_tok().range.clone();
+				p.bump(Self::separator());
+
+				if !Self::allow_trailing_separator() && p.at(Self::terminator()) {
+					p.error(
+						p.err_builder("trailing separator is not allowed here")
+							.primary(separator_range, "Remove this separator"),
+					);
+				}
+			} else if !p.at(Self::terminator()) {
+				p.expect_required(Self::separator());
+			}
+		}
+
+		m.complete(p, Self::list_kind())
+	}
+}
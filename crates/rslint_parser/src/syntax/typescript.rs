@@ -0,0 +1,125 @@
+///! Provides parsing for TypeScript-only constructs, starting with type annotations.
+use crate::ParsedSyntax::{Absent, Present};
+use crate::{CompletedMarker, ParsedSyntax, Parser, ParserProgress};
+use rslint_errors::Diagnostic;
+use rslint_syntax::SyntaxKind::{
+	EOF, TS_ARRAY_TYPE, TS_PREDEFINED_TYPE, TS_TUPLE_TYPE, TS_TYPE_ANNOTATION, TS_TYPE_REFERENCE,
+};
+use rslint_syntax::T;
+use std::ops::Range;
+
+/// Parses an optional TypeScript type annotation following a *complete* pattern, such as the
+/// `: [number, string]` in `const [a, b]: [number, string] = x` or the `: Props` in
+/// `function f({ a }: Props) {}`, and wraps it in a standalone [TS_TYPE_ANNOTATION] node that
+/// becomes the next sibling of whatever was just parsed (it does not wrap the pattern).
+///
+/// Called directly from [crate::syntax::pattern::ParseWithDefaultPattern::parse_pattern_with_optional_default]
+/// right after the pattern is parsed and before a `=` default is looked for, so it runs for
+/// every pattern parsed through that method (rest elements, which are parsed with
+/// `parse_pattern` directly, take no annotation) — both the top-level pattern of
+/// a declarator/parameter and, today, the per-element call inside array/object patterns too,
+/// since this tree slice has no declarator/parameter grammar of its own to own the annotation
+/// instead. TypeScript doesn't allow annotating individual destructuring elements, so that last
+/// part over-accepts; narrowing it to only the outermost pattern is left to whichever module
+/// ends up parsing declarators/parameters in the full crate.
+///
+/// Returns `Absent` if the parser isn't positioned at a `:` or the source isn't being parsed as
+/// TypeScript; in that second case the `:` is left untouched so it's reported as an unexpected
+/// token the same way it always has been.
+pub(crate) fn parse_pattern_type_annotation(p: &mut Parser) -> ParsedSyntax<CompletedMarker> {
+	if !p.at(T![:]) || !p.state.typescript {
+		return Absent;
+	}
+
+	let m = p.start();
+	p.bump_any(); // eat the : token
+
+	parse_ts_type(p).or_missing_with_error(p, expected_type_error);
+
+	Present(m.complete(p, TS_TYPE_ANNOTATION))
+}
+
+/// Parses a TypeScript type, such as the `number` in `: number` or the `[number, string]` in
+/// `: [number, string]`.
+///
+/// This only covers the small subset of the type grammar needed to parse a type annotation
+/// following a pattern (predefined types, type references, tuple types, and the `[]` array type
+/// suffix). Other type syntax (unions, conditional types, ...) is parsed by the rest of the
+/// TypeScript grammar.
+pub(crate) fn parse_ts_type(p: &mut Parser) -> ParsedSyntax<CompletedMarker> {
+	let ty = match parse_ts_primary_type(p) {
+		Present(ty) => ty,
+		Absent => return Absent,
+	};
+
+	Present(parse_ts_array_type_rest(p, ty))
+}
+
+/// Creates a diagnostic for a `:` that isn't followed by a type, e.g. `const x: = 5`. Kept
+/// separate from the caller's `expected_pattern_error` since the pattern before the `:` parsed
+/// fine here; it's the type that's missing.
+fn expected_type_error(p: &Parser, range: Range<usize>) -> Diagnostic {
+	p.err_builder("Expected a type").primary(range, "Expected a type here")
+}
+
+fn parse_ts_primary_type(p: &mut Parser) -> ParsedSyntax<CompletedMarker> {
+	match p.cur() {
+		T!['['] => parse_ts_tuple_type(p),
+		T![ident] if is_predefined_type_name(p.cur_src()) => {
+			let m = p.start();
+			p.bump_any();
+			Present(m.complete(p, TS_PREDEFINED_TYPE))
+		}
+		T![ident] => {
+			let m = p.start();
+			p.bump_any();
+			Present(m.complete(p, TS_TYPE_REFERENCE))
+		}
+		_ => Absent,
+	}
+}
+
+/// Parses a tuple type such as `[number, string]`.
+fn parse_ts_tuple_type(p: &mut Parser) -> ParsedSyntax<CompletedMarker> {
+	if !p.at(T!['[']) {
+		return Absent;
+	}
+
+	let m = p.start();
+	p.bump(T!['[']);
+	let mut progress = ParserProgress::default();
+
+	while !p.at(EOF) && !p.at(T![']']) {
+		progress.assert_progressing(p);
+
+		parse_ts_type(p).or_missing(p);
+
+		if !p.at(T![']']) {
+			p.expect_required(T![,]);
+		}
+	}
+
+	p.expect_required(T![']']);
+
+	Present(m.complete(p, TS_TUPLE_TYPE))
+}
+
+/// Wraps `ty` in a [TS_ARRAY_TYPE] node for every trailing `[]` suffix, e.g. turning the
+/// `number` in `number[][]` into `(number[])[]`.
+fn parse_ts_array_type_rest(p: &mut Parser, mut ty: CompletedMarker) -> CompletedMarker {
+	while p.at(T!['[']) && p.nth_at(1, T![']']) {
+		let m = ty.precede(p);
+		p.bump(T!['[']);
+		p.bump(T![']']);
+		ty = m.complete(p, TS_ARRAY_TYPE);
+	}
+
+	ty
+}
+
+fn is_predefined_type_name(name: &str) -> bool {
+	matches!(
+		name,
+		"any" | "unknown" | "number" | "boolean" | "string" | "void" | "undefined" | "null" | "never" | "object"
+	)
+}
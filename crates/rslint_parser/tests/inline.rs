@@ -0,0 +1,74 @@
+//! Generated by `cargo xtask codegen inline-tests`. Do not edit by hand.
+
+#[test]
+fn typed_array_pattern() {
+	let src = include_str!("../test_data/inline/ok/typed_array_pattern.js");
+	let parsed = rslint_parser::parse_text(src, 0);
+
+	assert!(parsed.errors().is_empty(), "expected no diagnostics, got {:#?}", parsed.errors());
+	insta::assert_snapshot!("typed_array_pattern", format!("{:#?}", parsed.syntax()));
+}
+
+#[test]
+fn typed_object_pattern() {
+	let src = include_str!("../test_data/inline/ok/typed_object_pattern.js");
+	let parsed = rslint_parser::parse_text(src, 0);
+
+	assert!(parsed.errors().is_empty(), "expected no diagnostics, got {:#?}", parsed.errors());
+	insta::assert_snapshot!("typed_object_pattern", format!("{:#?}", parsed.syntax()));
+}
+
+#[test]
+fn array_pattern() {
+	let src = include_str!("../test_data/inline/ok/array_pattern.js");
+	let parsed = rslint_parser::parse_text(src, 0);
+
+	assert!(parsed.errors().is_empty(), "expected no diagnostics, got {:#?}", parsed.errors());
+	insta::assert_snapshot!("array_pattern", format!("{:#?}", parsed.syntax()));
+}
+
+#[test]
+fn array_pattern_err() {
+	let src = include_str!("../test_data/inline/err/array_pattern_err.js");
+	let parsed = rslint_parser::parse_text(src, 0);
+
+	assert!(!parsed.errors().is_empty(), "expected at least one diagnostic, got none");
+	insta::assert_snapshot!("array_pattern_err", format!("{:#?}", parsed.syntax()));
+}
+
+#[test]
+fn rest_pattern() {
+	let src = include_str!("../test_data/inline/ok/rest_pattern.js");
+	let parsed = rslint_parser::parse_text(src, 0);
+
+	assert!(parsed.errors().is_empty(), "expected no diagnostics, got {:#?}", parsed.errors());
+	insta::assert_snapshot!("rest_pattern", format!("{:#?}", parsed.syntax()));
+}
+
+#[test]
+fn rest_pattern_err() {
+	let src = include_str!("../test_data/inline/err/rest_pattern_err.js");
+	let parsed = rslint_parser::parse_text(src, 0);
+
+	assert!(!parsed.errors().is_empty(), "expected at least one diagnostic, got none");
+	insta::assert_snapshot!("rest_pattern_err", format!("{:#?}", parsed.syntax()));
+}
+
+#[test]
+fn object_pattern() {
+	let src = include_str!("../test_data/inline/ok/object_pattern.js");
+	let parsed = rslint_parser::parse_text(src, 0);
+
+	assert!(parsed.errors().is_empty(), "expected no diagnostics, got {:#?}", parsed.errors());
+	insta::assert_snapshot!("object_pattern", format!("{:#?}", parsed.syntax()));
+}
+
+#[test]
+fn object_pattern_err() {
+	let src = include_str!("../test_data/inline/err/object_pattern_err.js");
+	let parsed = rslint_parser::parse_text(src, 0);
+
+	assert!(!parsed.errors().is_empty(), "expected at least one diagnostic, got none");
+	insta::assert_snapshot!("object_pattern_err", format!("{:#?}", parsed.syntax()));
+}
+
@@ -0,0 +1,152 @@
+///! Generates `.rast` snapshot tests from the `// test` and `// test_err` comment blocks that
+///! live directly above grammar functions in `rslint_parser::syntax`.
+///!
+///! Keeping a grammar function's regression fixtures physically next to the function itself
+///! (rather than in a separate, ever-drifting test file) means a change to the grammar and its
+///! test coverage show up in the same diff. This module is the codegen half of that convention:
+///! it scans the parser sources for the comment blocks, writes out the source snippets under
+///! `test_data/inline/{ok,err}/<name>.js`, and generates one Rust test per block that parses the
+///! snippet and snapshots the resulting tree (or, for `test_err`, asserts diagnostics exist).
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+/// A single `// test <name>` or `// test_err <name>` block found above a grammar function.
+struct InlineTest {
+	/// The name following `test`/`test_err`, used for the fixture file name and test fn name.
+	/// Must be a valid Rust identifier; [collect_inline_tests] asserts this.
+	name: String,
+	/// Whether the block is expected to produce diagnostics (`test_err`) or not (`test`).
+	ok: bool,
+	/// The commented-out source lines, with the leading `// ` stripped.
+	source: String,
+}
+
+/// Scans `text` (the contents of a single source file) for `// test`/`// test_err` blocks.
+///
+/// A block is any run of `// test <name>` or `// test_err <name>` followed immediately by one
+/// or more `// `-prefixed lines; ordinary `//` comments that don't start with `test`/`test_err`
+/// are left alone, and a function can be preceded by more than one block (each becomes its own
+/// fixture). Blocks are returned in source order so that regenerating fixtures produces minimal
+/// diffs.
+fn collect_inline_tests(text: &str) -> Vec<InlineTest> {
+	let mut tests = Vec::new();
+	let mut lines = text.lines().peekable();
+
+	while let Some(line) = lines.next() {
+		let trimmed = line.trim_start();
+		let (ok, rest) = if let Some(rest) = trimmed.strip_prefix("// test_err ") {
+			(false, rest)
+		} else if let Some(rest) = trimmed.strip_prefix("// test ") {
+			(true, rest)
+		} else {
+			continue;
+		};
+
+		let name = rest.trim().to_string();
+		assert!(
+			!name.is_empty()
+				&& name
+					.chars()
+					.all(|c| c.is_ascii_alphanumeric() || c == '_'),
+			"inline test name {:?} must be a valid Rust identifier (it's used as a #[test] fn name \
+			 and a fixture file name)",
+			name
+		);
+		let mut source = String::new();
+
+		while let Some(next) = lines.peek() {
+			let next_trimmed = next.trim_start();
+			if let Some(src_line) = next_trimmed.strip_prefix("// ") {
+				if src_line.starts_with("test ") || src_line.starts_with("test_err ") {
+					break;
+				}
+				writeln!(source, "{}", src_line).unwrap();
+				lines.next();
+			} else {
+				break;
+			}
+		}
+
+		tests.push(InlineTest { name, ok, source });
+	}
+
+	tests
+}
+
+/// Writes each collected test's source snippet to `test_data/inline/{ok,err}/<name>.js` under
+/// `out_dir`, creating the directories if needed.
+fn write_fixtures(out_dir: &Path, tests: &[InlineTest]) -> std::io::Result<()> {
+	for test in tests {
+		let sub_dir = if test.ok { "ok" } else { "err" };
+		let dir = out_dir.join("test_data/inline").join(sub_dir);
+		fs::create_dir_all(&dir)?;
+		fs::write(dir.join(format!("{}.js", test.name)), &test.source)?;
+	}
+
+	Ok(())
+}
+
+/// Renders the `#[test]` function for a single inline test.
+///
+/// Every test parses its fixture via `include_str!` (so the generated test file doesn't need a
+/// runtime dependency on `out_dir`'s layout) and snapshots the resulting tree with `insta`,
+/// keyed by the test's name so `cargo insta review` lines fixtures up with their source block.
+/// `test_err` blocks additionally assert that parsing produced at least one diagnostic, since
+/// their whole purpose is to pin down an error-recovery shape; `test` blocks assert the opposite,
+/// so a mistakenly malformed "happy path" fixture fails loudly instead of just drifting the
+/// snapshot.
+fn render_test_fn(test: &InlineTest) -> String {
+	let sub_dir = if test.ok { "ok" } else { "err" };
+	let fixture_path = format!("../test_data/inline/{}/{}.js", sub_dir, test.name);
+	let diagnostics_assertion = if test.ok {
+		"assert!(parsed.errors().is_empty(), \"expected no diagnostics, got {:#?}\", parsed.errors());"
+	} else {
+		"assert!(!parsed.errors().is_empty(), \"expected at least one diagnostic, got none\");"
+	};
+
+	format!(
+		"#[test]\nfn {name}() {{\n\tlet src = include_str!({fixture_path:?});\n\tlet parsed = rslint_parser::parse_text(src, 0);\n\n\t{diagnostics_assertion}\n\tinsta::assert_snapshot!({name:?}, format!(\"{{:#?}}\", parsed.syntax()));\n}}\n",
+		name = test.name,
+		fixture_path = fixture_path,
+		diagnostics_assertion = diagnostics_assertion,
+	)
+}
+
+/// Renders the full generated test file contents for `tests`: a header explaining the file is
+/// generated, followed by one `render_test_fn` per test in source order.
+fn render_test_file(tests: &[InlineTest]) -> String {
+	let mut out = String::new();
+	out.push_str("//! Generated by `cargo xtask codegen inline-tests`. Do not edit by hand.\n\n");
+
+	for test in tests {
+		out.push_str(&render_test_fn(test));
+		out.push('\n');
+	}
+
+	out
+}
+
+/// Scans every `.rs` file directly under `parser_src_dir` for inline test blocks, writes their
+/// fixtures and a generated test file under `out_dir`, and returns how many tests were found.
+pub(crate) fn generate(parser_src_dir: &Path, out_dir: &Path) -> std::io::Result<usize> {
+	let mut tests = Vec::new();
+
+	for entry in fs::read_dir(parser_src_dir)? {
+		let path = entry?.path();
+		if path.extension().and_then(|ext| ext.to_str()) != Some("rs") {
+			continue;
+		}
+
+		let text = fs::read_to_string(&path)?;
+		tests.extend(collect_inline_tests(&text));
+	}
+
+	write_fixtures(out_dir, &tests)?;
+
+	let tests_dir = out_dir.join("tests");
+	fs::create_dir_all(&tests_dir)?;
+	fs::write(tests_dir.join("inline.rs"), render_test_file(&tests))?;
+
+	Ok(tests.len())
+}
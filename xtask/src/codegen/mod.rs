@@ -0,0 +1,6 @@
+///! Codegen tasks run via `cargo xtask codegen`.
+///!
+///! This snapshot only carries the inline-test codegen below; other codegen tasks (AST node
+///! generation, syntax kind generation, ...) live in sibling modules that aren't part of this
+///! slice of the tree but are assumed declared here in the full crate.
+pub(crate) mod inline_tests;
@@ -0,0 +1,27 @@
+///! Entry point for developer-facing tasks run via `cargo xtask <task>`.
+///!
+///! This snapshot only dispatches the inline-test codegen task; other xtask subcommands (AST
+///! codegen, the syntax-kind generator, ...) live in sibling modules that aren't part of this
+///! slice of the tree but are assumed dispatched here in the full crate.
+mod codegen;
+
+use std::env;
+use std::path::Path;
+
+fn main() -> std::io::Result<()> {
+	let mut args = env::args().skip(1);
+
+	match (args.next().as_deref(), args.next().as_deref()) {
+		(Some("codegen"), Some("inline-tests")) => {
+			let parser_src_dir = Path::new("crates/rslint_parser/src/syntax");
+			let out_dir = Path::new("crates/rslint_parser");
+			let count = codegen::inline_tests::generate(parser_src_dir, out_dir)?;
+			println!("generated {} inline test(s)", count);
+			Ok(())
+		}
+		_ => {
+			eprintln!("usage: cargo xtask codegen inline-tests");
+			Ok(())
+		}
+	}
+}